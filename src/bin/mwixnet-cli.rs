@@ -0,0 +1,88 @@
+//! `mwixnet-cli` - submit and inspect swaps against a running mwixnet server from the
+//! command line, using the [`mwixnet::rpc_client`] library.
+
+use clap::{Parser, Subcommand};
+use grin_onion::create_onion;
+use mwixnet::crypto::{comsig::ComSignature, secp};
+use mwixnet::rpc_client::{self, RpcClient};
+use std::net::SocketAddr;
+
+#[derive(Parser)]
+#[command(
+	name = "mwixnet-cli",
+	about = "Submit and inspect swaps against a mwixnet server"
+)]
+struct Cli {
+	/// Base URL of the server's HTTP RPC endpoint, e.g. http://127.0.0.1:3420
+	#[arg(long)]
+	server: String,
+
+	/// SOCKS proxy to route requests through, for reaching onion-routed servers.
+	#[arg(long)]
+	socks_proxy: Option<SocketAddr>,
+
+	#[command(subcommand)]
+	command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+	/// Submit a commitment/onion for mixing.
+	Swap {
+		/// Value of the output being swapped, in nanogrin.
+		#[arg(long)]
+		value: u64,
+		/// Blinding factor of the wallet output being swapped, hex-encoded. Must be the real
+		/// key for an output you already own - the server checks the commitment it derives
+		/// against the live UTXO set, so a made-up one is always rejected as `CoinNotFound`.
+		#[arg(long)]
+		blind: String,
+	},
+	/// Query the server's operational status via the admin endpoint.
+	Status {
+		/// Base URL of the server's admin RPC endpoint.
+		#[arg(long)]
+		admin_url: String,
+		/// Admin bearer token.
+		#[arg(long)]
+		token: String,
+	},
+	/// Subscribe to round-completion updates for a previously submitted onion.
+	Watch {
+		/// WebSocket URL of the server, e.g. ws://127.0.0.1:3420
+		#[arg(long)]
+		ws_url: String,
+		/// Handle returned when the onion was submitted (its commitment, hex-encoded).
+		#[arg(long)]
+		handle: String,
+	},
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+	let cli = Cli::parse();
+
+	let client = match cli.socks_proxy {
+		Some(proxy) => RpcClient::with_socks_proxy(cli.server, proxy)?,
+		None => RpcClient::new(cli.server)?,
+	};
+
+	match cli.command {
+		Command::Swap { value, blind } => {
+			let blind = secp::SecretKey::from_slice(&grin_util::from_hex(&blind)?)?;
+			let commitment = secp::commit(value, &blind)?;
+			let onion = create_onion(&commitment, &vec![])?;
+			let comsig = ComSignature::sign(value, &blind, &onion.serialize()?)?;
+			let handle = client.swap(onion, comsig)?;
+			println!("swap submitted, handle: {}", handle);
+		}
+		Command::Status { admin_url, token } => {
+			let status = client.status(&admin_url, &token)?;
+			println!("{}", serde_json::to_string_pretty(&status)?);
+		}
+		Command::Watch { ws_url, handle } => {
+			rpc_client::watch(&ws_url, &handle, cli.socks_proxy)?;
+		}
+	}
+
+	Ok(())
+}
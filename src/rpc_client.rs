@@ -0,0 +1,283 @@
+use crate::crypto::secp;
+use crate::servers::swap::SwapError;
+pub use crate::servers::swap_rpc::onion_handle;
+use crate::servers::swap_rpc::SwapReq;
+
+use grin_onion::crypto::comsig::ComSignature;
+use grin_onion::onion::Onion;
+
+use hyper::client::HttpConnector;
+use hyper::{Body, Client, Request};
+use hyper_socks2::SocksConnector;
+use serde_json::Value;
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use tokio::runtime::Runtime;
+use tungstenite::Message;
+use url::Url;
+
+/// Error returned by an [`RpcClient`] call: either a transport failure, a response that
+/// didn't parse as JSON-RPC, or a [`SwapError`] surfaced by the server.
+#[derive(Debug)]
+pub enum ClientError {
+	Transport(String),
+	MalformedResponse(String),
+	Swap(SwapError),
+}
+
+impl std::fmt::Display for ClientError {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match self {
+			ClientError::Transport(e) => write!(f, "transport error: {}", e),
+			ClientError::MalformedResponse(e) => write!(f, "malformed response: {}", e),
+			ClientError::Swap(e) => write!(f, "{}", e),
+		}
+	}
+}
+
+impl std::error::Error for ClientError {}
+
+/// The transport a [`RpcClient`] dispatches requests over, either a direct connection or one
+/// routed through a SOCKS proxy (so a server published as a Tor hidden service is reachable).
+enum Transport {
+	Direct(Client<HttpConnector>),
+	Socks(Client<SocksConnector<HttpConnector>>),
+}
+
+impl Transport {
+	async fn request(&self, req: Request<Body>) -> Result<hyper::Response<Body>, ClientError> {
+		let result = match self {
+			Transport::Direct(client) => client.request(req).await,
+			Transport::Socks(client) => client.request(req).await,
+		};
+		result.map_err(|e| ClientError::Transport(e.to_string()))
+	}
+}
+
+/// A typed client for the `swap` JSON-RPC method exposed on a mwixnet server's `/v1` endpoint,
+/// and the bearer-token-gated admin namespace (`status`, `get_round_info`, `shutdown`).
+pub struct RpcClient {
+	server_url: String,
+	transport: Transport,
+	runtime: Runtime,
+}
+
+impl RpcClient {
+	/// Connects directly to `server_url` (e.g. `http://127.0.0.1:3420`).
+	pub fn new(server_url: String) -> std::io::Result<Self> {
+		Ok(RpcClient {
+			server_url,
+			transport: Transport::Direct(Client::new()),
+			runtime: Runtime::new()?,
+		})
+	}
+
+	/// Connects to `server_url` through a SOCKS5 proxy listening at `proxy_addr`.
+	pub fn with_socks_proxy(server_url: String, proxy_addr: SocketAddr) -> std::io::Result<Self> {
+		let connector = SocksConnector {
+			proxy_addr: format!("socks5://{}", proxy_addr)
+				.parse()
+				.expect("invalid SOCKS proxy address"),
+			auth: None,
+			connector: HttpConnector::new(),
+		};
+		Ok(RpcClient {
+			server_url,
+			transport: Transport::Socks(Client::builder().build(connector)),
+			runtime: Runtime::new()?,
+		})
+	}
+
+	/// Submits a commitment's onion for mixing. Returns the onion's handle (see
+	/// [`onion_handle`]), which the caller needs to later `watch` the onion's round outcome.
+	pub fn swap(&self, onion: Onion, comsig: ComSignature) -> Result<String, ClientError> {
+		let handle = onion_handle(&onion);
+		let swap = SwapReq::new(onion, comsig);
+		self.call(&self.server_url, None, "swap", serde_json::json!([swap]))?;
+		Ok(handle)
+	}
+
+	/// Fetches operational status (uptime, current round, pending onion count) from the
+	/// admin endpoint at `admin_url`.
+	pub fn status(&self, admin_url: &str, token: &str) -> Result<Value, ClientError> {
+		self.call(admin_url, Some(token), "status", serde_json::json!([]))
+	}
+
+	/// Fetches the outcome of a completed round from the admin endpoint at `admin_url`.
+	pub fn round_info(&self, admin_url: &str, token: &str, round: u64) -> Result<Value, ClientError> {
+		self.call(
+			admin_url,
+			Some(token),
+			"get_round_info",
+			serde_json::json!([round]),
+		)
+	}
+
+	fn call(
+		&self,
+		url: &str,
+		admin_token: Option<&str>,
+		method: &str,
+		params: Value,
+	) -> Result<Value, ClientError> {
+		let uri = format!("{}/v1", url);
+		let body = serde_json::json!({
+			"jsonrpc": "2.0",
+			"method": method,
+			"params": params,
+			"id": "1",
+		})
+		.to_string();
+
+		let mut request = Request::post(uri).header("Content-Type", "application/json");
+		if let Some(token) = admin_token {
+			request = request.header("Authorization", format!("Bearer {}", token));
+		}
+		let request = request
+			.body(Body::from(body))
+			.map_err(|e| ClientError::Transport(e.to_string()))?;
+
+		let response_str = self.runtime.block_on(async {
+			let response = self.transport.request(request).await?;
+			let bytes = hyper::body::to_bytes(response.into_body())
+				.await
+				.map_err(|e| ClientError::Transport(e.to_string()))?;
+			Ok::<_, ClientError>(String::from_utf8_lossy(&bytes).into_owned())
+		})?;
+
+		let response: Value = serde_json::from_str(&response_str)
+			.map_err(|e| ClientError::MalformedResponse(e.to_string()))?;
+
+		if let Some(error) = response.get("error") {
+			let message = error
+				.get("message")
+				.and_then(Value::as_str)
+				.unwrap_or("unknown error")
+				.to_string();
+			let swap_error = error
+				.get("data")
+				.and_then(reconstruct_swap_error)
+				.unwrap_or(SwapError::UnknownError(message));
+			return Err(ClientError::Swap(swap_error));
+		}
+
+		response
+			.get("result")
+			.cloned()
+			.ok_or_else(|| ClientError::MalformedResponse("missing result".to_string()))
+	}
+}
+
+/// Rebuilds the original `SwapError` variant from the `data` object a server attaches to a
+/// JSON-RPC error (see `impl From<SwapError> for jsonrpc_core::Error` in `servers::swap_rpc`).
+/// Variants with no structured data fall back to `SwapError::UnknownError`.
+fn reconstruct_swap_error(data: &Value) -> Option<SwapError> {
+	match data.get("variant")?.as_str()? {
+		"CoinNotFound" => {
+			let commit_hex = data.get("commit")?.as_str()?;
+			let commit = secp::Commitment::from_vec(grin_util::from_hex(commit_hex).ok()?);
+			Some(SwapError::CoinNotFound { commit })
+		}
+		_ => None,
+	}
+}
+
+/// Opens a raw TCP connection to `host:port`, routed through `socks_proxy` when given.
+/// Implements just enough of SOCKS5 (RFC 1928) - a no-auth greeting followed by a domain-name
+/// `CONNECT` - for tungstenite to perform the WS handshake over.
+fn tcp_connect(socks_proxy: Option<SocketAddr>, host: &str, port: u16) -> Result<TcpStream, ClientError> {
+	let proxy = match socks_proxy {
+		Some(proxy) => proxy,
+		None => return TcpStream::connect((host, port)).map_err(|e| ClientError::Transport(e.to_string())),
+	};
+
+	(|| -> std::io::Result<TcpStream> {
+		let mut stream = TcpStream::connect(proxy)?;
+
+		stream.write_all(&[0x05, 0x01, 0x00])?;
+		let mut greeting = [0u8; 2];
+		stream.read_exact(&mut greeting)?;
+		if greeting != [0x05, 0x00] {
+			return Err(std::io::Error::new(
+				std::io::ErrorKind::Other,
+				"SOCKS5 proxy rejected no-auth greeting",
+			));
+		}
+
+		let mut request = vec![0x05, 0x01, 0x00, 0x03, host.len() as u8];
+		request.extend_from_slice(host.as_bytes());
+		request.extend_from_slice(&port.to_be_bytes());
+		stream.write_all(&request)?;
+
+		let mut reply_header = [0u8; 4];
+		stream.read_exact(&mut reply_header)?;
+		if reply_header[1] != 0x00 {
+			return Err(std::io::Error::new(
+				std::io::ErrorKind::Other,
+				format!("SOCKS5 CONNECT failed with reply code {}", reply_header[1]),
+			));
+		}
+		let bound_addr_len = match reply_header[3] {
+			0x01 => 4,                                                     // IPv4
+			0x03 => {
+				let mut len = [0u8; 1];
+				stream.read_exact(&mut len)?;
+				len[0] as usize
+			}
+			0x04 => 16, // IPv6
+			other => {
+				return Err(std::io::Error::new(
+					std::io::ErrorKind::Other,
+					format!("SOCKS5 CONNECT returned unknown address type {}", other),
+				))
+			}
+		};
+		let mut bound_addr = vec![0u8; bound_addr_len + 2]; // + 2-byte port
+		stream.read_exact(&mut bound_addr)?;
+
+		Ok(stream)
+	})()
+	.map_err(|e| ClientError::Transport(e.to_string()))
+}
+
+/// Subscribes to round-completion updates for `handle` (an onion's commitment, hex-encoded -
+/// see `servers::swap_rpc::onion_handle`) over the WS endpoint at `ws_url`, printing each
+/// notification as it arrives and returning once a terminal `mixed`/`dropped` status lands.
+/// Routes the connection through `socks_proxy` when given.
+pub fn watch(ws_url: &str, handle: &str, socks_proxy: Option<SocketAddr>) -> Result<(), ClientError> {
+	let url = Url::parse(ws_url).map_err(|e| ClientError::Transport(e.to_string()))?;
+	let host = url
+		.host_str()
+		.ok_or_else(|| ClientError::Transport("WS URL is missing a host".to_string()))?;
+	let port = url
+		.port_or_known_default()
+		.ok_or_else(|| ClientError::Transport("WS URL is missing a port".to_string()))?;
+
+	let stream = tcp_connect(socks_proxy, host, port)?;
+	let (mut socket, _) =
+		tungstenite::client(url.as_str(), stream).map_err(|e| ClientError::Transport(e.to_string()))?;
+
+	let subscribe = serde_json::json!({
+		"jsonrpc": "2.0",
+		"method": "swap_subscribe",
+		"params": [handle],
+		"id": "1",
+	});
+	socket
+		.write_message(Message::Text(subscribe.to_string()))
+		.map_err(|e| ClientError::Transport(e.to_string()))?;
+
+	loop {
+		let msg = socket
+			.read_message()
+			.map_err(|e| ClientError::Transport(e.to_string()))?;
+		if let Message::Text(text) = msg {
+			println!("{}", text);
+			if text.contains("\"mixed\"") || text.contains("\"dropped\"") {
+				break;
+			}
+		}
+	}
+
+	Ok(())
+}
@@ -12,11 +12,24 @@ use jsonrpc_core::Value;
 use jsonrpc_derive::rpc;
 use jsonrpc_http_server::jsonrpc_core::*;
 use jsonrpc_http_server::*;
+use jsonrpc_pubsub::typed::Subscriber;
+use jsonrpc_pubsub::{PubSubHandler, PubSubMetadata, Session, SubscriptionId};
+use jsonrpc_ws_server::RequestContext;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
 use std::sync::{Arc, Mutex};
 use std::thread::{sleep, spawn};
 use std::time::Duration;
 
+use hyper::service::service_fn;
+use tokio::runtime::Runtime;
+use tokio::sync::oneshot;
+use tokio_rustls::rustls::{Certificate, PrivateKey, ServerConfig as TlsConfig};
+use tokio_rustls::TlsAcceptor;
+
 #[derive(Serialize, Deserialize)]
 pub struct SwapReq {
 	onion: Onion,
@@ -24,38 +37,391 @@ pub struct SwapReq {
 	comsig: ComSignature,
 }
 
+impl SwapReq {
+	/// Builds a `swap` request payload from a commitment's onion and the comsig authorizing
+	/// its use, exactly as `RPCSwapServer::swap` expects to deserialize it.
+	pub fn new(onion: Onion, comsig: ComSignature) -> Self {
+		SwapReq { onion, comsig }
+	}
+}
+
+/// Metadata attached to a WebSocket connection, used to tie a subscription back to its session.
+#[derive(Clone, Default)]
+pub struct Metadata {
+	session: Option<Arc<Session>>,
+}
+
+impl jsonrpc_core::Metadata for Metadata {}
+
+impl PubSubMetadata for Metadata {
+	fn session(&self) -> Option<Arc<Session>> {
+		self.session.clone()
+	}
+}
+
+/// Returns the handle used to correlate a submitted onion with its round-completion
+/// notifications. Derived from the onion's commitment so it's stable across the
+/// lifetime of a swap, without requiring the caller to invent its own id. `pub` so callers
+/// outside this module (e.g. `mwixnet-cli`) can print the handle a submitted onion needs for
+/// `swap_subscribe`/`watch`.
+pub fn onion_handle(onion: &Onion) -> String {
+	grin_util::to_hex(onion.commit.0.to_vec())
+}
+
+/// Loads a rustls server config from a PEM certificate chain and a PKCS#8 private key,
+/// for the optional TLS listener configured via `tls_cert_path`/`tls_key_path`.
+fn load_tls_config(cert_path: &Path, key_path: &Path) -> std::io::Result<TlsConfig> {
+	let cert_chain = rustls_pemfile::certs(&mut BufReader::new(File::open(cert_path)?))?
+		.into_iter()
+		.map(Certificate)
+		.collect();
+
+	let mut keys = rustls_pemfile::pkcs8_private_keys(&mut BufReader::new(File::open(key_path)?))?;
+	if keys.is_empty() {
+		return Err(std::io::Error::new(
+			std::io::ErrorKind::InvalidData,
+			format!("No PKCS#8 private key found in {:?}", key_path),
+		));
+	}
+	let key = PrivateKey(keys.remove(0));
+
+	TlsConfig::builder()
+		.with_safe_defaults()
+		.with_no_client_auth()
+		.with_single_cert(cert_chain, key)
+		.map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+}
+
+/// Answers a single `/v1` JSON-RPC request over an already-established connection,
+/// mirroring the routing `request_middleware` applies to the plaintext listener.
+async fn handle_tls_request(
+	io: Arc<IoHandler>,
+	req: hyper::Request<hyper::Body>,
+) -> Result<hyper::Response<hyper::Body>, hyper::Error> {
+	if req.uri() != "/v1" {
+		return Ok(hyper::Response::builder()
+			.status(hyper::StatusCode::BAD_REQUEST)
+			.body(hyper::Body::from("Only v1 supported"))
+			.unwrap());
+	}
+
+	let body = hyper::body::to_bytes(req.into_body()).await?;
+	let body_str = String::from_utf8_lossy(&body).into_owned();
+	let response = io
+		.handle_request(&body_str)
+		.await
+		.map(|r| format!("{}\n", r))
+		.unwrap_or_default();
+	Ok(hyper::Response::new(hyper::Body::from(response)))
+}
+
+/// A running HTTP(S) transport, abstracting over the plaintext `jsonrpc_http_server::Server`
+/// and the manually-driven TLS listener started when TLS is configured.
+enum HttpTransport {
+	Plain(jsonrpc_http_server::Server),
+	Tls {
+		accept_thread: std::thread::JoinHandle<()>,
+		shutdown: Arc<Mutex<Option<oneshot::Sender<()>>>>,
+	},
+}
+
+/// A handle that can request a running [`HttpTransport`] to stop accepting connections.
+enum HttpCloseHandle {
+	Plain(jsonrpc_http_server::CloseHandle),
+	Tls(Arc<Mutex<Option<oneshot::Sender<()>>>>),
+}
+
+impl HttpCloseHandle {
+	fn close(&self) {
+		match self {
+			HttpCloseHandle::Plain(handle) => handle.close(),
+			HttpCloseHandle::Tls(shutdown) => {
+				if let Some(tx) = shutdown.lock().unwrap().take() {
+					let _ = tx.send(());
+				}
+			}
+		}
+	}
+}
+
+impl HttpTransport {
+	fn close_handle(&self) -> HttpCloseHandle {
+		match self {
+			HttpTransport::Plain(server) => HttpCloseHandle::Plain(server.close_handle()),
+			HttpTransport::Tls { shutdown, .. } => HttpCloseHandle::Tls(shutdown.clone()),
+		}
+	}
+
+	fn wait(self) {
+		match self {
+			HttpTransport::Plain(server) => server.wait(),
+			HttpTransport::Tls { accept_thread, .. } => {
+				let _ = accept_thread.join();
+			}
+		}
+	}
+
+	fn close(self) {
+		self.close_handle().close();
+	}
+}
+
 #[rpc(server)]
 pub trait SwapAPI {
+	type Metadata;
+
+	/// Accepts one onion for mixing. A client aggregating many outputs ahead of a round
+	/// boundary can submit a JSON-RPC batch (an array of `swap` calls) in a single POST to
+	/// `/v1` instead of one round-trip per onion - `jsonrpc_core::IoHandler` dispatches each
+	/// entry independently, so one malformed or double-spent onion doesn't fail the rest of
+	/// the batch, and responses come back in the same order as the request array.
 	#[rpc(name = "swap")]
 	fn swap(&self, swap: SwapReq) -> jsonrpc_core::Result<Value>;
+
+	/// Subscribes to round-completion updates for a previously submitted onion,
+	/// identified by `handle` (see [`onion_handle`]).
+	#[pubsub(subscription = "swap_status", subscribe, name = "swap_subscribe")]
+	fn subscribe(&self, _: Self::Metadata, _: Subscriber<Value>, handle: String);
+
+	#[pubsub(subscription = "swap_status", unsubscribe, name = "swap_unsubscribe")]
+	fn unsubscribe(
+		&self,
+		_: Option<Self::Metadata>,
+		_: SubscriptionId,
+	) -> jsonrpc_core::Result<bool>;
+}
+
+/// Tally of what happened to the onions accepted into a single mixing round, returned by
+/// `get_round_info`. `execute_round` validates and mixes the round as a single atomic
+/// operation rather than per onion, so every onion accepted into a round shares its outcome:
+/// all `mixed` if the round succeeds, all `dropped` if it doesn't. This is round-level
+/// fidelity, not a report of which individual onions failed re-validation.
+#[derive(Clone, Serialize)]
+struct RoundInfo {
+	round: u64,
+	accepted: usize,
+	mixed: usize,
+	dropped: usize,
+}
+
+/// Admin-only control surface: operational status, round history, and graceful shutdown.
+/// Gated by a bearer token (see [`check_admin_auth`]) since, unlike `swap`,
+/// these methods have no comsig to authorize the caller.
+#[rpc(server)]
+pub trait AdminAPI {
+	#[rpc(name = "status")]
+	fn status(&self) -> jsonrpc_core::Result<Value>;
+
+	#[rpc(name = "get_round_info")]
+	fn get_round_info(&self, round: u64) -> jsonrpc_core::Result<Value>;
+
+	#[rpc(name = "shutdown")]
+	fn shutdown(&self) -> jsonrpc_core::Result<Value>;
 }
 
 #[derive(Clone)]
 struct RPCSwapServer {
 	server_config: ServerConfig,
 	server: Arc<Mutex<dyn SwapServer>>,
+	/// The next hop in the mix, if this isn't the last server in the chain. Held here (in
+	/// addition to the copy `SwapServerImpl` uses to forward onions) purely so `status` can
+	/// report whether it's currently reachable.
+	next_server: Option<Arc<dyn MixClient>>,
+	/// Subscribers waiting on the round outcome of a given onion, keyed by [`onion_handle`].
+	subscribers: Arc<Mutex<HashMap<String, jsonrpc_pubsub::typed::Sink<Value>>>>,
+	/// Handles of onions accepted via `swap()` since the last round boundary, registered
+	/// before `swap()` returns so a caller can't lose its notification by racing a
+	/// `swap_subscribe` against the round timer (see `subscribe`/`notify`). Also doubles as
+	/// the count of onions actually accepted this round, independent of `subscribers`.
+	pending_handles: Arc<Mutex<Vec<String>>>,
+	/// Round outcomes for handles that finished a round before anyone subscribed to them,
+	/// so a late `swap_subscribe` can be answered immediately instead of hanging forever.
+	/// Cleared as each outcome is claimed by `subscribe`.
+	outcomes: Arc<Mutex<HashMap<String, Value>>>,
+	/// Bearer token required on the admin namespace. `None` (no `admin_token_path`
+	/// configured) disables the admin methods entirely.
+	admin_token: Option<String>,
+	/// Number of onions accepted but not yet folded into a round.
+	pending: Arc<Mutex<usize>>,
+	/// Number of the most recently started round (0 before the first one), incremented
+	/// just before the round is executed.
+	round_number: Arc<Mutex<u64>>,
+	/// History of completed rounds, keyed by round number.
+	rounds: Arc<Mutex<HashMap<u64, RoundInfo>>>,
+	started_at: std::time::Instant,
+	stop_state: Arc<StopState>,
 }
 
 impl RPCSwapServer {
-	/// Spin up an instance of the JSON-RPC HTTP server.
-	fn start_http(&self) -> jsonrpc_http_server::Server {
+	/// Spin up an instance of the JSON-RPC HTTP server. Binds a plain HTTP listener, unless
+	/// `tls_cert_path`/`tls_key_path` are set in the server config, in which case connections
+	/// are TLS-terminated before being handed to the same `swap` handler.
+	fn start_http(&self) -> HttpTransport {
 		let mut io = IoHandler::new();
-		io.extend_with(RPCSwapServer::to_delegate(self.clone()));
+		io.extend_with(SwapAPI::to_delegate(self.clone()));
 
+		match (
+			&self.server_config.tls_cert_path,
+			&self.server_config.tls_key_path,
+		) {
+			(Some(cert_path), Some(key_path)) => {
+				let tls_config = load_tls_config(cert_path, key_path)
+					.expect("Invalid TLS certificate or key");
+				let (accept_thread, shutdown) = self.start_https(io, tls_config);
+				HttpTransport::Tls {
+					accept_thread,
+					shutdown,
+				}
+			}
+			_ => HttpTransport::Plain(
+				ServerBuilder::new(io)
+					.cors(DomainsValidation::Disabled)
+					.request_middleware(|request: hyper::Request<hyper::Body>| {
+						if request.uri() == "/v1" {
+							request.into()
+						} else {
+							jsonrpc_http_server::Response::bad_request("Only v1 supported").into()
+						}
+					})
+					.start_http(&self.server_config.addr)
+					.expect("Unable to start RPC server"),
+			),
+		}
+	}
+
+	/// Accepts TLS connections on `server_config.addr` and serves the `swap` handler over
+	/// them, running its own Tokio runtime on a dedicated thread since `listen()` is
+	/// otherwise synchronous.
+	fn start_https(
+		&self,
+		io: IoHandler,
+		tls_config: TlsConfig,
+	) -> (std::thread::JoinHandle<()>, Arc<Mutex<Option<oneshot::Sender<()>>>>) {
+		let addr = self.server_config.addr;
+		let io = Arc::new(io);
+		let acceptor = TlsAcceptor::from(Arc::new(tls_config));
+		let (shutdown_tx, mut shutdown_rx) = oneshot::channel();
+		let shutdown = Arc::new(Mutex::new(Some(shutdown_tx)));
+
+		let accept_thread = std::thread::spawn(move || {
+			let runtime = Runtime::new().expect("Unable to start TLS RPC runtime");
+			runtime.block_on(async move {
+				let listener = tokio::net::TcpListener::bind(addr)
+					.await
+					.expect("Unable to bind TLS RPC listener");
+				loop {
+					tokio::select! {
+						_ = &mut shutdown_rx => break,
+						accepted = listener.accept() => {
+							let stream = match accepted {
+								Ok((stream, _)) => stream,
+								Err(_) => continue,
+							};
+							let acceptor = acceptor.clone();
+							let io = io.clone();
+							tokio::spawn(async move {
+								if let Ok(tls_stream) = acceptor.accept(stream).await {
+									let service = service_fn(move |req| handle_tls_request(io.clone(), req));
+									let _ = hyper::server::conn::Http::new()
+										.serve_connection(tls_stream, service)
+										.await;
+								}
+							});
+						}
+					}
+				}
+			});
+		});
+
+		(accept_thread, shutdown)
+	}
+
+	/// Spin up a companion WebSocket server exposing the same `swap` method plus the
+	/// `swap_subscribe`/`swap_unsubscribe` pubsub pair, so a submitter can learn whether
+	/// its onion was folded into a round without polling. Bound to `server_config.ws_addr`,
+	/// a distinct address from the HTTP(S) listener's `addr` - both listeners run
+	/// unconditionally, so reusing the same socket address would make the second `start`
+	/// fail with "address already in use".
+	fn start_ws(&self) -> jsonrpc_ws_server::Server {
+		let mut io = PubSubHandler::new(MetaIoHandler::default());
+		io.extend_with(SwapAPI::to_delegate(self.clone()));
+
+		jsonrpc_ws_server::ServerBuilder::with_meta_extractor(
+			io,
+			|context: &RequestContext| Metadata {
+				session: Some(Arc::new(Session::new(context.sender()))),
+			},
+		)
+		.start(&self.server_config.ws_addr)
+		.expect("Unable to start WS RPC server")
+	}
+
+	/// Delivers the round outcome for `handle` (the round's outcome, not a per-onion
+	/// re-validation result - see the caveat on [`RoundInfo`]). If a subscriber is already
+	/// waiting, notifies it directly and drops the subscription; otherwise the subscription
+	/// hasn't arrived yet, so the outcome is stashed in `outcomes` for `subscribe` to hand
+	/// back as soon as it does.
+	fn notify(&self, handle: &str, status: Value) {
+		match self.subscribers.lock().unwrap().remove(handle) {
+			Some(sink) => {
+				let _ = sink.notify(Ok(status));
+			}
+			None => {
+				self.outcomes.lock().unwrap().insert(handle.to_string(), status);
+			}
+		}
+	}
+
+	/// Spin up the admin JSON-RPC server, bound to its own address and gated by the bearer
+	/// token generated at startup. A separate listener (rather than folding `status`/
+	/// `get_round_info`/`shutdown` into the submitter-facing `/v1` endpoint) keeps the
+	/// comsig-authorized `swap` path free of any token handling.
+	fn start_admin(&self) -> jsonrpc_http_server::Server {
+		let mut io = IoHandler::new();
+		io.extend_with(AdminAPI::to_delegate(self.clone()));
+
+		let admin_token = self.admin_token.clone();
 		ServerBuilder::new(io)
 			.cors(DomainsValidation::Disabled)
-			.request_middleware(|request: hyper::Request<hyper::Body>| {
-				if request.uri() == "/v1" {
+			.request_middleware(move |request: hyper::Request<hyper::Body>| {
+				if check_admin_auth(&admin_token, request.headers()) {
 					request.into()
 				} else {
-					jsonrpc_http_server::Response::bad_request("Only v1 supported").into()
+					jsonrpc_http_server::Response::bad_request("Unauthorized").into()
 				}
 			})
-			.start_http(&self.server_config.addr)
-			.expect("Unable to start RPC server")
+			.start_http(
+				&self
+					.server_config
+					.admin_addr
+					.expect("start_admin called without an admin_addr configured"),
+			)
+			.expect("Unable to start admin RPC server")
 	}
 }
 
+/// Checks the `Authorization: Bearer <token>` header against the admin token generated at
+/// startup. `None` (admin disabled, no token path configured) always rejects.
+fn check_admin_auth(token: &Option<String>, headers: &hyper::HeaderMap) -> bool {
+	match token {
+		Some(expected) => headers
+			.get(hyper::header::AUTHORIZATION)
+			.and_then(|v| v.to_str().ok())
+			.map(|v| constant_time_eq(v.as_bytes(), format!("Bearer {}", expected).as_bytes()))
+			.unwrap_or(false),
+		None => false,
+	}
+}
+
+/// Compares two byte strings in time independent of where they first differ, so a timing
+/// attack can't be used to guess the admin token one byte at a time.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+	if a.len() != b.len() {
+		return false;
+	}
+	a.iter().zip(b.iter()).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
 impl From<SwapError> for Error {
 	fn from(e: SwapError) -> Self {
 		match e {
@@ -64,19 +430,92 @@ impl From<SwapError> for Error {
 				code: ErrorCode::InternalError,
 				data: None,
 			},
+			// Echoes enough structured `data` for `rpc_client::RpcClient::call` to rebuild
+			// the original `SwapError` variant instead of only seeing its message - see
+			// `rpc_client::reconstruct_swap_error`. Variants not handled here still surface
+			// as `invalid_params` with just a message, same as before.
+			SwapError::CoinNotFound { ref commit } => Error {
+				message: e.to_string(),
+				code: ErrorCode::InvalidParams,
+				data: Some(serde_json::json!({
+					"variant": "CoinNotFound",
+					"commit": grin_util::to_hex(commit.0.to_vec()),
+				})),
+			},
 			_ => Error::invalid_params(e.to_string()),
 		}
 	}
 }
 
 impl SwapAPI for RPCSwapServer {
+	type Metadata = Metadata;
+
 	fn swap(&self, swap: SwapReq) -> jsonrpc_core::Result<Value> {
 		self.server
 			.lock()
 			.unwrap()
 			.swap(&swap.onion, &swap.comsig)?;
+		*self.pending.lock().unwrap() += 1;
+		self.pending_handles
+			.lock()
+			.unwrap()
+			.push(onion_handle(&swap.onion));
 		Ok(Value::String("success".into()))
 	}
+
+	fn subscribe(&self, _meta: Self::Metadata, subscriber: Subscriber<Value>, handle: String) {
+		// The round this handle belongs to may already have completed before this
+		// subscription arrived - serve the stashed outcome immediately instead of waiting
+		// on a notification that already fired (see `notify`).
+		if let Some(status) = self.outcomes.lock().unwrap().remove(&handle) {
+			if let Ok(sink) = subscriber.assign_id(SubscriptionId::String(handle)) {
+				let _ = sink.notify(Ok(status));
+			}
+			return;
+		}
+
+		let sink = match subscriber.assign_id(SubscriptionId::String(handle.clone())) {
+			Ok(sink) => sink,
+			Err(_) => return,
+		};
+		self.subscribers.lock().unwrap().insert(handle, sink);
+	}
+
+	fn unsubscribe(
+		&self,
+		_meta: Option<Self::Metadata>,
+		id: SubscriptionId,
+	) -> jsonrpc_core::Result<bool> {
+		if let SubscriptionId::String(handle) = id {
+			Ok(self.subscribers.lock().unwrap().remove(&handle).is_some())
+		} else {
+			Ok(false)
+		}
+	}
+}
+
+impl AdminAPI for RPCSwapServer {
+	fn status(&self) -> jsonrpc_core::Result<Value> {
+		Ok(serde_json::json!({
+			"uptime_s": self.started_at.elapsed().as_secs(),
+			"round": *self.round_number.lock().unwrap(),
+			"pending_onions": *self.pending.lock().unwrap(),
+			// `null` when this server is the last hop and has no next server configured.
+			"next_server_reachable": self.next_server.as_ref().map(|c| c.is_reachable()),
+		}))
+	}
+
+	fn get_round_info(&self, round: u64) -> jsonrpc_core::Result<Value> {
+		match self.rounds.lock().unwrap().get(&round) {
+			Some(info) => Ok(serde_json::json!(info)),
+			None => Err(Error::invalid_params(format!("Round {} not found", round))),
+		}
+	}
+
+	fn shutdown(&self) -> jsonrpc_core::Result<Value> {
+		self.stop_state.stop();
+		Ok(Value::String("shutting down".into()))
+	}
 }
 
 /// Spin up the JSON-RPC web server
@@ -90,26 +529,50 @@ pub fn listen(
 ) -> std::result::Result<(), Box<dyn std::error::Error>> {
 	let server = SwapServerImpl::new(
 		server_config.clone(),
-		next_server,
+		next_server.clone(),
 		wallet.clone(),
 		node.clone(),
 		store,
 	);
 	let server = Arc::new(Mutex::new(server));
 
+	let admin_token = server_config
+		.admin_token_path
+		.as_ref()
+		.map(|path| generate_admin_token(path))
+		.transpose()?;
+
 	let rpc_server = RPCSwapServer {
 		server_config: server_config.clone(),
 		server: server.clone(),
+		next_server,
+		subscribers: Arc::new(Mutex::new(HashMap::new())),
+		admin_token,
+		pending: Arc::new(Mutex::new(0)),
+		pending_handles: Arc::new(Mutex::new(Vec::new())),
+		outcomes: Arc::new(Mutex::new(HashMap::new())),
+		round_number: Arc::new(Mutex::new(0)),
+		rounds: Arc::new(Mutex::new(HashMap::new())),
+		started_at: std::time::Instant::now(),
+		stop_state: stop_state.clone(),
 	};
 
 	let http_server = rpc_server.start_http();
+	let ws_server = rpc_server.start_ws();
+	let admin_server = server_config.admin_addr.map(|_| rpc_server.start_admin());
 
 	let close_handle = http_server.close_handle();
+	let ws_close_handle = ws_server.close_handle();
+	let admin_close_handle = admin_server.as_ref().map(|s| s.close_handle());
 	let round_handle = spawn(move || {
 		let mut secs = 0;
 		loop {
 			if stop_state.is_stopped() {
 				close_handle.close();
+				ws_close_handle.close();
+				if let Some(handle) = &admin_close_handle {
+					handle.close();
+				}
 				break;
 			}
 
@@ -117,17 +580,72 @@ pub fn listen(
 			secs = (secs + 1) % server_config.interval_s;
 
 			if secs == 0 {
-				let _ = server.lock().unwrap().execute_round();
+				// The handles accepted this round, registered by `swap()` itself rather
+				// than collected from `subscribers` - a caller may submit over HTTP without
+				// ever subscribing, or subscribe after this tick already fired, so
+				// subscriber count is neither an accurate `accepted` total nor a complete
+				// notify list.
+				let handles: Vec<String> =
+					std::mem::take(&mut *rpc_server.pending_handles.lock().unwrap());
+				let accepted = handles.len();
+				let round = {
+					let mut round_number = rpc_server.round_number.lock().unwrap();
+					*round_number += 1;
+					*round_number
+				};
+
+				// `execute_round` validates and mixes every onion accepted into this round as
+				// one atomic operation - there's no per-onion result to thread through, so
+				// every handle accepted this round shares the round's outcome below.
+				let (mixed, dropped) = match server.lock().unwrap().execute_round() {
+					Ok(_) => {
+						for handle in &handles {
+							rpc_server.notify(handle, serde_json::json!({ "status": "mixed", "round": round }));
+						}
+						(accepted, 0)
+					}
+					Err(_) => {
+						for handle in &handles {
+							rpc_server.notify(handle, serde_json::json!({ "status": "dropped", "round": round }));
+						}
+						(0, accepted)
+					}
+				};
+
+				*rpc_server.pending.lock().unwrap() -= accepted;
+				rpc_server.rounds.lock().unwrap().insert(
+					round,
+					RoundInfo {
+						round,
+						accepted,
+						mixed,
+						dropped,
+					},
+				);
 			}
 		}
 	});
 
 	http_server.wait();
+	if let Some(admin_server) = admin_server {
+		admin_server.wait();
+	}
 	round_handle.join().unwrap();
 
 	Ok(())
 }
 
+/// Generates a fresh admin bearer token and persists it to `path`, so an operator can read
+/// it back out-of-band (e.g. to pass to `mwixnet-cli status --token`).
+fn generate_admin_token(path: &std::path::Path) -> std::result::Result<String, Box<dyn std::error::Error>> {
+	use std::os::unix::fs::PermissionsExt;
+
+	let token = grin_util::to_hex(crate::crypto::secp::random_secret().0.to_vec());
+	std::fs::write(path, &token)?;
+	std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))?;
+	Ok(token)
+}
+
 #[cfg(test)]
 mod tests {
 	use crate::config::ServerConfig;
@@ -138,26 +656,38 @@ mod tests {
 	use crate::servers::swap_rpc::{RPCSwapServer, SwapReq};
 
 	use grin_onion::create_onion;
+	use grin_util::StopState;
+	use std::collections::HashMap;
 	use std::net::TcpListener;
 	use std::sync::{Arc, Mutex};
 
 	use hyper::{Body, Client, Request, Response};
 	use tokio::runtime::Runtime;
 
+	use super::load_tls_config;
+
 	async fn body_to_string(req: Response<Body>) -> String {
 		let body_bytes = hyper::body::to_bytes(req.into_body()).await.unwrap();
 		String::from_utf8(body_bytes.to_vec()).unwrap()
 	}
 
-	/// Spin up a temporary web service, query the API, then cleanup and return response
-	fn make_request(
+	/// Spin up a temporary web service, query the API, then cleanup and return response.
+	/// When `tls` is set, the listener is TLS-terminated and the request is made over
+	/// `https://` against a self-signed certificate.
+	fn make_request_with_tls(
 		server: Arc<Mutex<dyn SwapServer>>,
 		req: String,
+		tls: Option<(std::path::PathBuf, std::path::PathBuf)>,
 	) -> Result<String, Box<dyn std::error::Error>> {
+		let (tls_cert_path, tls_key_path) = match &tls {
+			Some((cert, key)) => (Some(cert.clone()), Some(key.clone())),
+			None => (None, None),
+		};
 		let server_config = ServerConfig {
 			key: secp::random_secret(),
 			interval_s: 1,
 			addr: TcpListener::bind("127.0.0.1:0")?.local_addr()?,
+			ws_addr: TcpListener::bind("127.0.0.1:0")?.local_addr()?,
 			socks_proxy_addr: TcpListener::bind("127.0.0.1:0")?.local_addr()?,
 			grin_node_url: "127.0.0.1:3413".parse()?,
 			grin_node_secret_path: None,
@@ -165,17 +695,32 @@ mod tests {
 			wallet_owner_secret_path: None,
 			prev_server: None,
 			next_server: None,
+			tls_cert_path,
+			tls_key_path,
+			admin_addr: None,
+			admin_token_path: None,
 		};
 
 		let rpc_server = RPCSwapServer {
 			server_config: server_config.clone(),
 			server: server.clone(),
+			next_server: None,
+			subscribers: Arc::new(Mutex::new(HashMap::new())),
+			admin_token: None,
+			pending: Arc::new(Mutex::new(0)),
+			pending_handles: Arc::new(Mutex::new(Vec::new())),
+			outcomes: Arc::new(Mutex::new(HashMap::new())),
+			round_number: Arc::new(Mutex::new(0)),
+			rounds: Arc::new(Mutex::new(HashMap::new())),
+			started_at: std::time::Instant::now(),
+			stop_state: Arc::new(StopState::new()),
 		};
 
 		// Start the JSON-RPC server
 		let http_server = rpc_server.start_http();
 
-		let uri = format!("http://{}/v1", server_config.addr);
+		let scheme = if tls.is_some() { "https" } else { "http" };
+		let uri = format!("{}://{}/v1", scheme, server_config.addr);
 
 		let threaded_rt = Runtime::new()?;
 		let do_request = async move {
@@ -184,7 +729,11 @@ mod tests {
 				.body(Body::from(req))
 				.unwrap();
 
-			Client::new().request(request).await
+			if tls.is_some() {
+				insecure_https_client().request(request).await
+			} else {
+				Client::new().request(request).await
+			}
 		};
 
 		let response = threaded_rt.block_on(do_request)?;
@@ -202,6 +751,62 @@ mod tests {
 		Ok(response_str)
 	}
 
+	fn make_request(
+		server: Arc<Mutex<dyn SwapServer>>,
+		req: String,
+	) -> Result<String, Box<dyn std::error::Error>> {
+		make_request_with_tls(server, req, None)
+	}
+
+	/// A client that trusts any server certificate, since tests terminate TLS with a
+	/// freshly-generated self-signed certificate that isn't in any trust store.
+	fn insecure_https_client() -> Client<hyper_rustls::HttpsConnector<hyper::client::HttpConnector>>
+	{
+		let tls = tokio_rustls::rustls::ClientConfig::builder()
+			.with_safe_defaults()
+			.with_custom_certificate_verifier(std::sync::Arc::new(NoCertVerification))
+			.with_no_client_auth();
+		let connector = hyper_rustls::HttpsConnectorBuilder::new()
+			.with_tls_config(tls)
+			.https_only()
+			.enable_http1()
+			.build();
+		Client::builder().build(connector)
+	}
+
+	struct NoCertVerification;
+
+	impl tokio_rustls::rustls::client::ServerCertVerifier for NoCertVerification {
+		fn verify_server_cert(
+			&self,
+			_end_entity: &tokio_rustls::rustls::Certificate,
+			_intermediates: &[tokio_rustls::rustls::Certificate],
+			_server_name: &tokio_rustls::rustls::ServerName,
+			_scts: &mut dyn Iterator<Item = &[u8]>,
+			_ocsp_response: &[u8],
+			_now: std::time::SystemTime,
+		) -> Result<
+			tokio_rustls::rustls::client::ServerCertVerified,
+			tokio_rustls::rustls::Error,
+		> {
+			Ok(tokio_rustls::rustls::client::ServerCertVerified::assertion())
+		}
+	}
+
+	/// Generates a throwaway self-signed certificate/key pair for the TLS tests, written to
+	/// PEM files under a temporary directory.
+	fn generate_self_signed_cert(
+	) -> Result<(std::path::PathBuf, std::path::PathBuf), Box<dyn std::error::Error>> {
+		let cert = rcgen::generate_simple_self_signed(vec!["localhost".into()])?;
+		let dir = std::env::temp_dir().join(format!("mwixnet-tls-test-{:?}", std::thread::current().id()));
+		std::fs::create_dir_all(&dir)?;
+		let cert_path = dir.join("cert.pem");
+		let key_path = dir.join("key.pem");
+		std::fs::write(&cert_path, cert.serialize_pem()?)?;
+		std::fs::write(&key_path, cert.serialize_private_key_pem())?;
+		Ok((cert_path, key_path))
+	}
+
 	// todo: Test all error types
 
 	/// Demonstrates a successful swap response
@@ -275,4 +880,162 @@ mod tests {
 		assert_eq!(response, expected);
 		Ok(())
 	}
+
+	/// A JSON-RPC batch of `swap` calls is answered with one result per request, in the same
+	/// order they were submitted, and a failing onion in the batch doesn't fail its
+	/// neighbors - `jsonrpc_core::IoHandler` already dispatches each batch entry
+	/// independently, so this just pins down the behavior a wallet submitting many outputs
+	/// at once relies on.
+	#[test]
+	fn swap_batch_mixed_success_and_failure() -> Result<(), Box<dyn std::error::Error>> {
+		let good_commitment = secp::commit(1234, &secp::random_secret())?;
+		let good_onion = create_onion(&good_commitment, &vec![])?;
+		let good_comsig =
+			ComSignature::sign(1234, &secp::random_secret(), &good_onion.serialize()?)?;
+		let good_swap = SwapReq {
+			onion: good_onion.clone(),
+			comsig: good_comsig,
+		};
+
+		let bad_commitment = secp::commit(5678, &secp::random_secret())?;
+		let bad_onion = create_onion(&bad_commitment, &vec![])?;
+		let bad_comsig =
+			ComSignature::sign(5678, &secp::random_secret(), &bad_onion.serialize()?)?;
+		let bad_swap = SwapReq {
+			onion: bad_onion.clone(),
+			comsig: bad_comsig,
+		};
+
+		let mut server = MockSwapServer::new();
+		server.set_response(
+			&bad_onion,
+			SwapError::CoinNotFound {
+				commit: bad_commitment.clone(),
+			},
+		);
+		let server: Arc<Mutex<dyn SwapServer>> = Arc::new(Mutex::new(server));
+
+		let req = format!(
+			"[{{\"jsonrpc\": \"2.0\", \"method\": \"swap\", \"params\": [{}], \"id\": \"1\"}}, \
+			  {{\"jsonrpc\": \"2.0\", \"method\": \"swap\", \"params\": [{}], \"id\": \"2\"}}]",
+			serde_json::json!(good_swap),
+			serde_json::json!(bad_swap)
+		);
+		let response = make_request(server, req)?;
+		let bad_data = serde_json::json!({
+			"variant": "CoinNotFound",
+			"commit": grin_util::to_hex(bad_commitment.0.to_vec()),
+		});
+		let expected = format!(
+            "[{{\"jsonrpc\":\"2.0\",\"result\":\"success\",\"id\":\"1\"}},{{\"jsonrpc\":\"2.0\",\"error\":{{\"code\":-32602,\"message\":\"Output {:?} does not exist, or is already spent.\",\"data\":{}}},\"id\":\"2\"}}]\n",
+            bad_commitment, bad_data
+        );
+		assert_eq!(response, expected);
+		Ok(())
+	}
+
+	/// A `swap` call against the TLS-terminated listener succeeds the same way it does
+	/// over plain HTTP.
+	#[test]
+	fn swap_success_over_tls() -> Result<(), Box<dyn std::error::Error>> {
+		let (cert_path, key_path) = generate_self_signed_cert()?;
+
+		let commitment = secp::commit(1234, &secp::random_secret())?;
+		let onion = create_onion(&commitment, &vec![])?;
+		let comsig = ComSignature::sign(1234, &secp::random_secret(), &onion.serialize()?)?;
+		let swap = SwapReq {
+			onion: onion.clone(),
+			comsig,
+		};
+
+		let server: Arc<Mutex<dyn SwapServer>> = Arc::new(Mutex::new(MockSwapServer::new()));
+
+		let req = format!(
+			"{{\"jsonrpc\": \"2.0\", \"method\": \"swap\", \"params\": [{}], \"id\": \"1\"}}",
+			serde_json::json!(swap)
+		);
+		let response = make_request_with_tls(server, req, Some((cert_path, key_path)))?;
+		let expected = "{\"jsonrpc\":\"2.0\",\"result\":\"success\",\"id\":\"1\"}\n";
+		assert_eq!(response, expected);
+
+		Ok(())
+	}
+
+	/// A malformed key file produces a clear startup error rather than a panic deep in rustls.
+	#[test]
+	fn tls_config_rejects_malformed_key() -> Result<(), Box<dyn std::error::Error>> {
+		let (cert_path, _) = generate_self_signed_cert()?;
+		let bad_key_path = cert_path.with_file_name("not-a-key.pem");
+		std::fs::write(&bad_key_path, b"not a pem file")?;
+
+		assert!(load_tls_config(&cert_path, &bad_key_path).is_err());
+		Ok(())
+	}
+
+	/// `status` rejects requests without the admin bearer token, and serves them once the
+	/// correct one is supplied.
+	#[test]
+	fn admin_status_requires_token() -> Result<(), Box<dyn std::error::Error>> {
+		let server_config = ServerConfig {
+			key: secp::random_secret(),
+			interval_s: 1,
+			addr: TcpListener::bind("127.0.0.1:0")?.local_addr()?,
+			ws_addr: TcpListener::bind("127.0.0.1:0")?.local_addr()?,
+			socks_proxy_addr: TcpListener::bind("127.0.0.1:0")?.local_addr()?,
+			grin_node_url: "127.0.0.1:3413".parse()?,
+			grin_node_secret_path: None,
+			wallet_owner_url: "127.0.0.1:3420".parse()?,
+			wallet_owner_secret_path: None,
+			prev_server: None,
+			next_server: None,
+			tls_cert_path: None,
+			tls_key_path: None,
+			admin_addr: Some(TcpListener::bind("127.0.0.1:0")?.local_addr()?),
+			admin_token_path: None,
+		};
+
+		let server: Arc<Mutex<dyn SwapServer>> = Arc::new(Mutex::new(MockSwapServer::new()));
+		let rpc_server = RPCSwapServer {
+			server_config: server_config.clone(),
+			server,
+			next_server: None,
+			subscribers: Arc::new(Mutex::new(HashMap::new())),
+			admin_token: Some("s3cr3t".to_string()),
+			pending: Arc::new(Mutex::new(0)),
+			pending_handles: Arc::new(Mutex::new(Vec::new())),
+			outcomes: Arc::new(Mutex::new(HashMap::new())),
+			round_number: Arc::new(Mutex::new(0)),
+			rounds: Arc::new(Mutex::new(HashMap::new())),
+			started_at: std::time::Instant::now(),
+			stop_state: Arc::new(StopState::new()),
+		};
+		let admin_server = rpc_server.start_admin();
+		let uri = format!("http://{}", server_config.admin_addr.unwrap());
+		let req_body = "{\"jsonrpc\": \"2.0\", \"method\": \"status\", \"params\": [], \"id\": \"1\"}";
+
+		let threaded_rt = Runtime::new()?;
+		let unauthorized = threaded_rt.block_on(async {
+			let request = Request::post(&uri)
+				.header("Content-Type", "application/json")
+				.body(Body::from(req_body))
+				.unwrap();
+			Client::new().request(request).await
+		})?;
+		assert_eq!(unauthorized.status(), hyper::StatusCode::BAD_REQUEST);
+
+		let authorized = threaded_rt.block_on(async {
+			let request = Request::post(&uri)
+				.header("Content-Type", "application/json")
+				.header("Authorization", "Bearer s3cr3t")
+				.body(Body::from(req_body))
+				.unwrap();
+			Client::new().request(request).await
+		})?;
+		let response_str = threaded_rt.block_on(body_to_string(authorized));
+		threaded_rt.shutdown_background();
+		admin_server.close();
+
+		assert!(response_str.contains("\"pending_onions\":0"));
+		Ok(())
+	}
 }